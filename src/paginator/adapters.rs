@@ -79,6 +79,535 @@ fn test_enumerate_paginator() {
     assert_eq!(o.next(), Some((1, &"World")));
 }
 
+/// Struct created by [Paginator::chunks]. See that method for more information.
+#[must_use = "paginators are lazy and do nothing unless consumed"]
+pub struct Chunks<A> {
+    pub(crate) page_size: usize,
+    pub(crate) inner: A,
+    /// Lengths of the pages already produced while walking forward, so that
+    /// `previous` can walk the inner paginator back one page at a time and
+    /// reconstruct a short final page instead of assuming `page_size`.
+    pub(crate) history: Vec<usize>,
+}
+
+impl<A: Paginator> Paginator for Chunks<A> {
+    type Item = Vec<A::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut page = Vec::with_capacity(self.page_size);
+        for _ in 0..self.page_size {
+            match self.inner.next() {
+                Some(item) => page.push(item),
+                None => break,
+            }
+        }
+
+        if page.is_empty() {
+            return None;
+        }
+
+        self.history.push(page.len());
+        Some(page)
+    }
+
+    #[inline]
+    fn previous(&mut self) -> Option<Self::Item> {
+        let len = self.history.pop()?;
+        let mut page = Vec::with_capacity(len);
+        for _ in 0..len {
+            match self.inner.previous() {
+                Some(item) => page.push(item),
+                None => break,
+            }
+        }
+
+        page.reverse();
+        Some(page)
+    }
+}
+
+impl<A> Chunks<A> {
+    /// Returns the 1-indexed page number of the page most recently returned
+    /// by `next` (or, symmetrically, the page `previous` would re-emit next
+    /// if called again), or `0` if no page has been yielded yet.
+    #[inline]
+    pub fn page_number(&self) -> usize {
+        self.history.len()
+    }
+}
+
+#[test]
+fn test_chunks_paginator() {
+    use crate::paginator::Paginate as _;
+    use std::num::NonZeroUsize;
+
+    let page_size = NonZeroUsize::new(2).unwrap();
+    let items = vec![0, 1, 2, 3, 4];
+    let mut pag = items.paginate().chunks(page_size);
+
+    assert_eq!(pag.next(), Some(vec![&0, &1]));
+    assert_eq!(pag.page_number(), 1);
+    assert_eq!(pag.next(), Some(vec![&2, &3]));
+    assert_eq!(pag.page_number(), 2);
+    assert_eq!(pag.next(), Some(vec![&4]));
+    assert_eq!(pag.page_number(), 3);
+    assert_eq!(pag.next(), None);
+    assert_eq!(pag.previous(), Some(vec![&4]));
+    assert_eq!(pag.page_number(), 2);
+    assert_eq!(pag.previous(), Some(vec![&2, &3]));
+    assert_eq!(pag.previous(), Some(vec![&0, &1]));
+    assert_eq!(pag.page_number(), 0);
+    assert_eq!(pag.previous(), None);
+}
+
+/// Struct created by [Paginator::rev]. See that method for more information.
+#[must_use = "paginators are lazy and do nothing unless consumed"]
+pub struct Rev<A> {
+    pub(crate) inner: A,
+    /// Whether the inner paginator has already been driven to its end, so
+    /// that `previous` has something to walk back over. Done lazily, on
+    /// first use, to keep this adapter's construction side-effect-free.
+    pub(crate) reached_end: bool,
+}
+
+impl<A: Paginator> Rev<A> {
+    #[inline]
+    fn reach_end(&mut self) {
+        if !self.reached_end {
+            while self.inner.next().is_some() {}
+            self.reached_end = true;
+        }
+    }
+}
+
+impl<A: Paginator> Paginator for Rev<A> {
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reach_end();
+        self.inner.previous()
+    }
+
+    #[inline]
+    fn previous(&mut self) -> Option<Self::Item> {
+        self.reach_end();
+        self.inner.next()
+    }
+}
+
+#[test]
+fn test_rev_paginator() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2];
+    let mut pag = items.paginate().rev();
+
+    assert_eq!(pag.next(), Some(&2));
+    assert_eq!(pag.next(), Some(&1));
+    assert_eq!(pag.next(), Some(&0));
+    assert_eq!(pag.next(), None);
+}
+
+#[test]
+fn test_rev_rev_is_identity() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2];
+    let mut pag = items.paginate().rev().rev();
+
+    assert_eq!(pag.next(), Some(&0));
+    assert_eq!(pag.next(), Some(&1));
+    assert_eq!(pag.next(), Some(&2));
+    assert_eq!(pag.next(), None);
+}
+
+/// Struct created by [Paginator::zip]. See that method for more information.
+#[must_use = "paginators are lazy and do nothing unless consumed"]
+pub struct Zip<A, B> {
+    pub(crate) inner_a: A,
+    pub(crate) inner_b: B,
+}
+
+impl<A: Paginator, B: Paginator> Paginator for Zip<A, B> {
+    type Item = (A::Item, B::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.inner_a.next()?;
+        match self.inner_b.next() {
+            Some(b) => Some((a, b)),
+            None => {
+                self.inner_a.previous();
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn previous(&mut self) -> Option<Self::Item> {
+        let b = self.inner_b.previous()?;
+        match self.inner_a.previous() {
+            Some(a) => Some((a, b)),
+            None => {
+                self.inner_b.next();
+                None
+            }
+        }
+    }
+}
+
+#[test]
+fn test_zip_paginator() {
+    use crate::paginator::Paginate as _;
+
+    let a = vec![0, 1, 2];
+    let b = vec!["a", "b"];
+
+    let mut pag = a.paginate().zip(b.paginate());
+
+    assert_eq!(pag.next(), Some((&0, &"a")));
+    assert_eq!(pag.next(), Some((&1, &"b")));
+    assert_eq!(pag.next(), None);
+
+    assert_eq!(pag.previous(), Some((&1, &"b")));
+    assert_eq!(pag.previous(), Some((&0, &"a")));
+    assert_eq!(pag.previous(), None);
+}
+
+/// Struct created by [Paginator::peekable]. See that method for more information.
+#[must_use = "paginators are lazy and do nothing unless consumed"]
+pub struct Peek<A: Paginator> {
+    pub(crate) inner: A,
+    pub(crate) ahead: Option<A::Item>,
+    pub(crate) behind: Option<A::Item>,
+}
+
+impl<A: Paginator> Peek<A> {
+    /// Looks at the next element without consuming it.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&A::Item> {
+        if self.behind.is_some() {
+            // Same reconciliation `next` does: undo `peek_back`'s pull
+            // before asking the inner paginator for the real next element.
+            self.inner.next();
+            self.behind = None;
+        }
+        if self.ahead.is_none() {
+            self.ahead = self.inner.next();
+        }
+        self.ahead.as_ref()
+    }
+
+    /// Looks at the previous element without consuming it.
+    #[inline]
+    pub fn peek_back(&mut self) -> Option<&A::Item> {
+        if self.ahead.is_some() {
+            // Symmetric case: undo `peek`'s pull before asking the inner
+            // paginator for the real previous element.
+            self.inner.previous();
+            self.ahead = None;
+        }
+        if self.behind.is_none() {
+            self.behind = self.inner.previous();
+        }
+        self.behind.as_ref()
+    }
+}
+
+impl<A: Paginator> Paginator for Peek<A> {
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.behind.is_some() {
+            // `peek_back` pulled one element behind the logical cursor via
+            // `inner.previous()`; undo that pull so `next` doesn't re-read
+            // the very element `peek_back` already buffered.
+            self.inner.next();
+            self.behind = None;
+        }
+        self.ahead.take().or_else(|| self.inner.next())
+    }
+
+    #[inline]
+    fn previous(&mut self) -> Option<Self::Item> {
+        if self.ahead.is_some() {
+            // Symmetric case: undo `peek`'s `inner.next()` pull before
+            // asking the inner paginator for the real previous element.
+            self.inner.previous();
+            self.ahead = None;
+        }
+        self.behind.take().or_else(|| self.inner.previous())
+    }
+}
+
+#[test]
+fn test_peekable_paginator() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2];
+    let mut pag = items.paginate().peekable();
+
+    assert_eq!(pag.peek(), Some(&&0));
+    assert_eq!(pag.peek(), Some(&&0));
+    assert_eq!(pag.next(), Some(&0));
+    assert_eq!(pag.next(), Some(&1));
+
+    assert_eq!(pag.peek_back(), Some(&&1));
+    assert_eq!(pag.previous(), Some(&1));
+    assert_eq!(pag.next(), Some(&1));
+    assert_eq!(pag.next(), Some(&2));
+    assert_eq!(pag.peek(), None);
+    assert_eq!(pag.next(), None);
+}
+
+#[test]
+fn test_peekable_peek_then_opposite_direction() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2];
+    let mut pag = items.paginate().peekable();
+
+    assert_eq!(pag.peek(), Some(&&0));
+    assert_eq!(pag.previous(), None);
+    assert_eq!(pag.next(), Some(&0));
+    assert_eq!(pag.next(), Some(&1));
+
+    let mut pag = items.paginate().peekable();
+    assert_eq!(pag.next(), Some(&0));
+    assert_eq!(pag.peek_back(), Some(&&0));
+    assert_eq!(pag.next(), Some(&1));
+    assert_eq!(pag.next(), Some(&2));
+}
+
+#[test]
+fn test_peekable_peek_then_peek_back() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2];
+    let mut pag = items.paginate().peekable();
+
+    // Nothing precedes a fresh paginator's first element, even after
+    // peeking ahead.
+    assert_eq!(pag.peek(), Some(&&0));
+    assert_eq!(pag.peek_back(), None);
+    assert_eq!(pag.next(), Some(&0));
+
+    // Symmetric case: nothing follows the last element, even after
+    // peeking behind.
+    assert_eq!(pag.next(), Some(&1));
+    assert_eq!(pag.next(), Some(&2));
+    assert_eq!(pag.peek_back(), Some(&&2));
+    assert_eq!(pag.peek(), None);
+}
+
+/// Struct created by [Paginator::filter]. See that method for more information.
+#[must_use = "paginators are lazy and do nothing unless consumed"]
+pub struct Filter<A, P> {
+    pub(crate) inner: A,
+    pub(crate) predicate: P,
+}
+
+impl<A: Paginator, P: Fn(&A::Item) -> bool> Paginator for Filter<A, P> {
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.inner.next()?;
+            if (self.predicate)(&item) {
+                return Some(item);
+            }
+        }
+    }
+
+    #[inline]
+    fn previous(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.inner.previous()?;
+            if (self.predicate)(&item) {
+                return Some(item);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_filter_paginator() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2, 3, 4, 5];
+    let mut pag = items.paginate().filter(|&&n| n % 2 == 0);
+
+    assert_eq!(pag.next(), Some(&0));
+    assert_eq!(pag.next(), Some(&2));
+    assert_eq!(pag.next(), Some(&4));
+    assert_eq!(pag.next(), None);
+    assert_eq!(pag.previous(), Some(&4));
+    assert_eq!(pag.previous(), Some(&2));
+    assert_eq!(pag.previous(), Some(&0));
+    assert_eq!(pag.previous(), None);
+}
+
+/// Struct created by [Paginator::skip_while]. See that method for more information.
+#[must_use = "paginators are lazy and do nothing unless consumed"]
+pub struct SkipWhile<A, P> {
+    pub(crate) inner: A,
+    pub(crate) predicate: P,
+    pub(crate) done: bool,
+    /// Net number of elements this adapter has moved the inner paginator
+    /// forward (`next` adds one, `previous` subtracts one), so `previous` can tell whether the cursor has backed up past
+    /// `skip_end` and the skip needs to re-arm.
+    pub(crate) position: usize,
+    /// `position` at the moment the skip completed. `None` until `done`
+    /// first becomes true.
+    pub(crate) skip_end: Option<usize>,
+}
+
+impl<A: Paginator, P: Fn(&A::Item) -> bool> Paginator for SkipWhile<A, P> {
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return self.inner.next().inspect(|_| self.position += 1);
+        }
+
+        loop {
+            let item = self.inner.next()?;
+            self.position += 1;
+            if !(self.predicate)(&item) {
+                self.done = true;
+                self.skip_end = Some(self.position);
+                return Some(item);
+            }
+        }
+    }
+
+    #[inline]
+    fn previous(&mut self) -> Option<Self::Item> {
+        // `skip_end` is only `None` while the forward skip scan hasn't yet
+        // found a non-matching element — either no `next()` has run, or the
+        // last one exhausted the inner paginator without ever finding a
+        // split point. In that state every remaining element backward still
+        // matches the predicate and was never actually yielded, so `previous`
+        // must re-apply the predicate exactly like `next`'s scan does,
+        // rather than handing back a raw "should-be-skipped" element.
+        if self.skip_end.is_none() {
+            loop {
+                let item = self.inner.previous()?;
+                self.position = self.position.saturating_sub(1);
+                if !(self.predicate)(&item) {
+                    return Some(item);
+                }
+            }
+        }
+
+        self.inner.previous().inspect(|_| {
+            self.position = self.position.saturating_sub(1);
+            if self.skip_end.is_some_and(|end| self.position < end) {
+                self.done = false;
+            }
+        })
+    }
+}
+
+#[test]
+fn test_skip_while_paginator() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2, 3, 0];
+    let mut pag = items.paginate().skip_while(|&&n| n < 2);
+
+    assert_eq!(pag.next(), Some(&2));
+    assert_eq!(pag.next(), Some(&3));
+    assert_eq!(pag.next(), Some(&0));
+    assert_eq!(pag.next(), None);
+    assert_eq!(pag.previous(), Some(&0));
+    assert_eq!(pag.previous(), Some(&3));
+    assert_eq!(pag.previous(), Some(&2));
+    assert_eq!(pag.previous(), Some(&1));
+    assert_eq!(pag.previous(), Some(&0));
+    assert_eq!(pag.previous(), None);
+
+    // Having rewound all the way back to the true start, the skip re-arms:
+    // a fresh forward pass must re-apply the predicate instead of replaying
+    // the originally-skipped elements raw.
+    assert_eq!(pag.next(), Some(&2));
+    assert_eq!(pag.next(), Some(&3));
+}
+
+#[test]
+fn test_skip_while_paginator_predicate_matches_everything() {
+    use crate::paginator::Paginate as _;
+
+    // The predicate matches every element, so the forward scan exhausts
+    // the inner paginator without ever finding a split point: `done` never
+    // latches. `previous` must still treat these as skipped rather than
+    // leaking the last one back out.
+    let items = vec![0, 1];
+    let mut pag = items.paginate().skip_while(|&&n| n < 2);
+
+    assert_eq!(pag.next(), None);
+    assert_eq!(pag.previous(), None);
+}
+
+/// Struct created by [Paginator::take_while]. See that method for more information.
+#[must_use = "paginators are lazy and do nothing unless consumed"]
+pub struct TakeWhile<A, P> {
+    pub(crate) inner: A,
+    pub(crate) predicate: P,
+    pub(crate) done: bool,
+}
+
+impl<A: Paginator, P: Fn(&A::Item) -> bool> Paginator for TakeWhile<A, P> {
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.inner.next() {
+            Some(item) if (self.predicate)(&item) => Some(item),
+            Some(_) => {
+                // Put the failing element back so `previous` never re-yields it.
+                self.inner.previous();
+                self.done = true;
+                None
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn previous(&mut self) -> Option<Self::Item> {
+        self.done = false;
+        self.inner.previous()
+    }
+}
+
+#[test]
+fn test_take_while_paginator() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2, 3, 0];
+    let mut pag = items.paginate().take_while(|&&n| n < 2);
+
+    assert_eq!(pag.next(), Some(&0));
+    assert_eq!(pag.next(), Some(&1));
+    assert_eq!(pag.next(), None);
+    assert_eq!(pag.previous(), Some(&1));
+    assert_eq!(pag.previous(), Some(&0));
+    assert_eq!(pag.previous(), None);
+}
+
 /// Struct created by [Paginator::chain]. See that method for more information..
 #[must_use = "paginators are lazy and do nothing unless consumed"]
 pub struct Chain<A, B> {
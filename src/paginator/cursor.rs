@@ -0,0 +1,376 @@
+//! Keyset (cursor) pagination over an ordered, externally-fetched backing
+//! store, for collections (e.g. database tables) where offset indexing is
+//! too expensive or unstable under concurrent writes.
+
+use std::ops::Bound;
+
+use super::page::{Page, PageRequestError};
+use super::Paginator;
+
+/// Which direction a [CursorPaginator] fetch should walk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+/// A windowed request into a [CursorPaginator], using opaque key cursors
+/// instead of numeric offsets so pages stay stable across concurrent inserts.
+/// Kept distinct from [page::PageRequest](super::page::PageRequest) (used by
+/// offset-based windowing) because `after`/`before` here carry a key of type
+/// `K` rather than a raw `usize` offset; [Page] and [PageRequestError] are
+/// shared between the two.
+#[derive(Debug, Clone)]
+pub struct PageRequest<K> {
+    pub first: Option<usize>,
+    pub last: Option<usize>,
+    pub after: Option<K>,
+    pub before: Option<K>,
+}
+
+impl<K> Default for PageRequest<K> {
+    fn default() -> Self {
+        PageRequest {
+            first: None,
+            last: None,
+            after: None,
+            before: None,
+        }
+    }
+}
+
+/// A paginator over an ordered collection addressed by `K` keys rather than
+/// numeric indices. `fetch` is called with a bound on the last-seen key, a
+/// direction, and a requested count, and must return up to that many rows
+/// at or beyond the bound, each paired with its own key. The bound is
+/// `Excluded` when continuing in the same direction, but `Included` when
+/// `next`/`previous` reverses direction and needs to re-surface the row it
+/// just crossed.
+///
+/// `fetch`'s ordering contract: rows must come back nearest-to-the-bound
+/// first. For `Direction::Forward` that means ascending by key; for
+/// `Direction::Backward` it means *descending* by key. [CursorPaginator::page]
+/// relies on this to know which of an over-fetched batch to keep when
+/// truncating to the requested count, and `next`/`previous` rely on it to
+/// pick the correct single row.
+pub struct CursorPaginator<K, Item, F>
+where
+    F: FnMut(Bound<&K>, Direction, usize) -> Vec<(K, Item)>,
+{
+    fetch: F,
+    boundary: Bound<K>,
+    /// Which direction produced `boundary`, so that reversing direction
+    /// queries it with `Bound::Included` (re-surface the row just crossed)
+    /// instead of `Bound::Excluded` (which would skip one row further and
+    /// break the `next()`-then-`previous()` round-trip that
+    /// [VecPag](super::VecPag) also guarantees).
+    last_step: Option<Direction>,
+}
+
+impl<K, Item, F> CursorPaginator<K, Item, F>
+where
+    K: Ord + Clone,
+    F: FnMut(Bound<&K>, Direction, usize) -> Vec<(K, Item)>,
+{
+    /// Creates a cursor paginator with no bias: the first `next()` fetches
+    /// from the front of the backing store, the first `previous()` from the
+    /// back. This differs from a freshly created [VecPag](super::VecPag),
+    /// whose first `previous()` returns `None`.
+    pub fn new(fetch: F) -> Self {
+        CursorPaginator {
+            fetch,
+            boundary: Bound::Unbounded,
+            last_step: None,
+        }
+    }
+
+    /// Fetches a windowed [Page] described by `request`, advancing the
+    /// stored boundary key to the edge of the returned window.
+    pub fn page(&mut self, request: PageRequest<K>) -> Result<Page<Item>, PageRequestError> {
+        if request.first.is_some() && request.last.is_some() {
+            return Err(PageRequestError::ConflictingDirections);
+        }
+
+        if let (Some(after), Some(before)) = (&request.after, &request.before) {
+            if after >= before {
+                return Err(PageRequestError::AfterPastBefore);
+            }
+        }
+
+        if request.before.is_some() && request.last.is_none() {
+            return Err(PageRequestError::MismatchedCursor);
+        }
+
+        if request.after.is_some() && request.first.is_none() {
+            return Err(PageRequestError::MismatchedCursor);
+        }
+
+        if let Some(last) = request.last {
+            let bound = request
+                .before
+                .map(Bound::Excluded)
+                .unwrap_or(Bound::Unbounded);
+            let mut rows = (self.fetch)(bound.as_ref(), Direction::Backward, last.saturating_add(1));
+
+            let has_previous = rows.len() > last;
+            if has_previous {
+                rows.truncate(last);
+            }
+            rows.reverse();
+
+            if let Some((key, _)) = rows.first() {
+                self.boundary = Bound::Excluded(key.clone());
+                self.last_step = Some(Direction::Backward);
+            }
+
+            // Probe forward from the window's last row (or, if the window
+            // came back empty, from the original bound) to find out whether
+            // anything actually follows, rather than assuming `before` alone
+            // implies more data.
+            let has_next = match rows.last() {
+                Some((key, _)) => {
+                    !(self.fetch)(Bound::Excluded(key.clone()).as_ref(), Direction::Forward, 1)
+                        .is_empty()
+                }
+                None => !(self.fetch)(bound.as_ref(), Direction::Forward, 1).is_empty(),
+            };
+
+            return Ok(Page {
+                items: rows.into_iter().map(|(_, item)| item).collect(),
+                has_previous,
+                has_next,
+            });
+        }
+
+        let first = request.first.unwrap_or(usize::MAX);
+        let bound = request
+            .after
+            .map(Bound::Excluded)
+            .unwrap_or(Bound::Unbounded);
+        let mut rows = (self.fetch)(bound.as_ref(), Direction::Forward, first.saturating_add(1));
+
+        let has_next = rows.len() > first;
+        if has_next {
+            rows.truncate(first);
+        }
+
+        if let Some((key, _)) = rows.last() {
+            self.boundary = Bound::Excluded(key.clone());
+            self.last_step = Some(Direction::Forward);
+        }
+
+        // Symmetric probe: is there anything backward of the window's first
+        // row (or the original bound, if the window is empty)?
+        let has_previous = match rows.first() {
+            Some((key, _)) => {
+                !(self.fetch)(Bound::Excluded(key.clone()).as_ref(), Direction::Backward, 1)
+                    .is_empty()
+            }
+            None => !(self.fetch)(bound.as_ref(), Direction::Backward, 1).is_empty(),
+        };
+
+        Ok(Page {
+            items: rows.into_iter().map(|(_, item)| item).collect(),
+            has_previous,
+            has_next,
+        })
+    }
+}
+
+impl<K, Item, F> Paginator for CursorPaginator<K, Item, F>
+where
+    K: Ord + Clone,
+    F: FnMut(Bound<&K>, Direction, usize) -> Vec<(K, Item)>,
+{
+    type Item = Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // Reversing out of a backward step must re-surface the row that
+        // step just crossed, not skip past it, so query it inclusively.
+        let query = match (&self.boundary, self.last_step) {
+            (Bound::Excluded(key), Some(Direction::Backward)) => Bound::Included(key.clone()),
+            (boundary, _) => boundary.clone(),
+        };
+        let (key, item) = (self.fetch)(query.as_ref(), Direction::Forward, 1).pop()?;
+        self.boundary = Bound::Excluded(key);
+        self.last_step = Some(Direction::Forward);
+        Some(item)
+    }
+
+    #[inline]
+    fn previous(&mut self) -> Option<Self::Item> {
+        // Symmetric reversal out of a forward step.
+        let query = match (&self.boundary, self.last_step) {
+            (Bound::Excluded(key), Some(Direction::Forward)) => Bound::Included(key.clone()),
+            (boundary, _) => boundary.clone(),
+        };
+        let (key, item) = (self.fetch)(query.as_ref(), Direction::Backward, 1).pop()?;
+        self.boundary = Bound::Excluded(key);
+        self.last_step = Some(Direction::Backward);
+        Some(item)
+    }
+}
+
+/// Builds a mock `fetch` closure over `rows` for tests: filters by
+/// `bound`/`direction` like a real keyset query would (honoring `Included`
+/// bounds too, used by `next`/`previous` to re-surface a row crossed by a
+/// direction reversal), then, for `Direction::Backward`, reverses the
+/// result so rows come back nearest-to-the-bound first, per `fetch`'s
+/// ordering contract.
+#[cfg(test)]
+fn test_fetch<'a>(
+    rows: &'a [(usize, &'a str)],
+) -> impl FnMut(Bound<&usize>, Direction, usize) -> Vec<(usize, &'a str)> {
+    move |bound: Bound<&usize>, direction: Direction, count: usize| -> Vec<(usize, &'a str)> {
+        let mut matching: Vec<(usize, &str)> = rows
+            .iter()
+            .filter(|(key, _)| match (direction, bound) {
+                (Direction::Forward, Bound::Excluded(b)) => key > b,
+                (Direction::Forward, Bound::Included(b)) => key >= b,
+                (Direction::Forward, Bound::Unbounded) => true,
+                (Direction::Backward, Bound::Excluded(b)) => key < b,
+                (Direction::Backward, Bound::Included(b)) => key <= b,
+                (Direction::Backward, Bound::Unbounded) => true,
+            })
+            .cloned()
+            .collect();
+
+        match direction {
+            Direction::Forward => {
+                matching.truncate(count);
+                matching
+            }
+            Direction::Backward => {
+                // Honor the fetch contract: Backward rows come back
+                // nearest-to-the-bound first, i.e. descending by key.
+                let skip = matching.len().saturating_sub(count);
+                let mut nearest_first = matching[skip..].to_vec();
+                nearest_first.reverse();
+                nearest_first
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cursor_paginator_next_previous() {
+    let rows: Vec<(usize, &str)> = vec![(0, "a"), (1, "b"), (2, "c")];
+
+    let mut pag = CursorPaginator::new(test_fetch(&rows));
+
+    assert_eq!(pag.next(), Some("a"));
+    assert_eq!(pag.next(), Some("b"));
+    assert_eq!(pag.next(), Some("c"));
+    assert_eq!(pag.next(), None);
+    assert_eq!(pag.previous(), Some("c"));
+    assert_eq!(pag.previous(), Some("b"));
+    assert_eq!(pag.previous(), Some("a"));
+    assert_eq!(pag.previous(), None);
+}
+
+#[test]
+fn test_cursor_paginator_next_previous_interleaved() {
+    // Like VecPag, next() then previous() returns the same element, even
+    // without draining in one direction first.
+    let rows: Vec<(usize, &str)> = vec![(0, "a"), (1, "b"), (2, "c")];
+
+    let mut pag = CursorPaginator::new(test_fetch(&rows));
+
+    assert_eq!(pag.next(), Some("a"));
+    assert_eq!(pag.previous(), Some("a"));
+    assert_eq!(pag.next(), Some("a"));
+    assert_eq!(pag.next(), Some("b"));
+    assert_eq!(pag.previous(), Some("b"));
+    assert_eq!(pag.previous(), Some("a"));
+}
+
+#[test]
+fn test_cursor_paginator_page() {
+    let rows: Vec<(usize, &str)> = vec![(0, "a"), (1, "b"), (2, "c"), (3, "d")];
+
+    let mut pag = CursorPaginator::new(test_fetch(&rows));
+
+    let page = pag
+        .page(PageRequest {
+            first: Some(2),
+            after: Some(0),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(page.items, vec!["b", "c"]);
+    assert!(page.has_previous);
+    assert!(page.has_next);
+
+    let result = pag.page(PageRequest {
+        first: Some(1),
+        last: Some(1),
+        ..Default::default()
+    });
+    assert_eq!(result.err(), Some(PageRequestError::ConflictingDirections));
+
+    let result = pag.page(PageRequest {
+        first: Some(1),
+        after: Some(2),
+        before: Some(1),
+        ..Default::default()
+    });
+    assert_eq!(result.err(), Some(PageRequestError::AfterPastBefore));
+
+    let result = pag.page(PageRequest {
+        first: Some(1),
+        after: Some(1),
+        before: Some(1),
+        ..Default::default()
+    });
+    assert_eq!(result.err(), Some(PageRequestError::AfterPastBefore));
+
+    let result = pag.page(PageRequest {
+        first: Some(2),
+        before: Some(1),
+        ..Default::default()
+    });
+    assert_eq!(result.err(), Some(PageRequestError::MismatchedCursor));
+
+    let result = pag.page(PageRequest {
+        last: Some(2),
+        after: Some(1),
+        ..Default::default()
+    });
+    assert_eq!(result.err(), Some(PageRequestError::MismatchedCursor));
+}
+
+#[test]
+fn test_cursor_paginator_page_last() {
+    let rows: Vec<(usize, &str)> = vec![(0, "a"), (1, "b"), (2, "c"), (3, "d"), (4, "e")];
+
+    let mut pag = CursorPaginator::new(test_fetch(&rows));
+
+    // Over-fetching (last + 1 == 3) must keep the two rows *nearest* the
+    // `before` boundary (c, d), not the two farthest from it (a, b).
+    let page = pag
+        .page(PageRequest {
+            last: Some(2),
+            before: Some(4),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(page.items, vec!["c", "d"]);
+    assert!(page.has_previous);
+    assert!(page.has_next);
+
+    // Plain "give me the last N" with no `before` walks back from the true
+    // end of the collection.
+    let page = pag
+        .page(PageRequest {
+            last: Some(2),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(page.items, vec!["d", "e"]);
+    assert!(page.has_previous);
+    assert!(!page.has_next);
+}
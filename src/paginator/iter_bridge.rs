@@ -0,0 +1,91 @@
+//! Bridges between [Paginator] and the standard [Iterator] / [DoubleEndedIterator] ecosystem.
+
+use super::Paginator;
+
+/// Struct created by [Paginator::into_iter_bridge]. See that method for more
+/// information.
+pub struct PaginatorIter<P> {
+    pub(crate) inner: P,
+}
+
+impl<P: Paginator> Iterator for PaginatorIter<P> {
+    type Item = P::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<P: Paginator> PaginatorIter<P> {
+    /// Steps backward, undoing the last forward step, exactly like
+    /// [Paginator::previous]. This is *not* [DoubleEndedIterator::next_back]:
+    /// that method must hand back an unconsumed element from the tail of
+    /// whatever's left (so a fresh iterator's `next_back()` yields the last
+    /// element, and interleaved `next()`/`next_back()` calls converge from
+    /// both ends), which `Paginator::previous`'s undo semantics can't
+    /// provide — `previous()` on a fresh paginator returns `None`, and
+    /// calling it right after `next()` re-yields the same element rather
+    /// than a different one. So `PaginatorIter` only bridges to [Iterator],
+    /// not [DoubleEndedIterator]; this method exposes the undo behavior
+    /// directly instead of hiding it behind a trait whose contract it
+    /// doesn't meet.
+    #[inline]
+    pub fn previous(&mut self) -> Option<P::Item> {
+        self.inner.previous()
+    }
+}
+
+/// A [Paginator] built from a [DoubleEndedIterator] by [from_double_ended].
+/// `next` pulls from the front, `previous` pulls from the back.
+pub struct FromDoubleEnded<I> {
+    inner: I,
+}
+
+impl<I: DoubleEndedIterator> Paginator for FromDoubleEnded<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn previous(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+/// Wraps any [DoubleEndedIterator] as a [Paginator], the inverse of
+/// [Paginator::into_iter_bridge]. This gives bidirectional pagination to
+/// arrays, slices, and [std::collections::VecDeque] for free. Unlike
+/// `VecPag`, `previous` does not undo a `next`: it pulls the next unconsumed
+/// element from the back.
+pub fn from_double_ended<I: DoubleEndedIterator>(iter: I) -> FromDoubleEnded<I> {
+    FromDoubleEnded { inner: iter }
+}
+
+#[test]
+fn test_into_iter_bridge() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2, 3];
+    let mut pag = items.paginate().into_iter_bridge();
+
+    let collected: Vec<_> = (&mut pag).take(4).collect();
+    assert_eq!(collected, vec![&0, &1, &2, &3]);
+
+    // `previous` undoes the forward walk, not a `next_back`-style tail pull.
+    assert_eq!(pag.previous(), Some(&3));
+    assert_eq!(pag.previous(), Some(&2));
+}
+
+#[test]
+fn test_from_double_ended() {
+    let mut pag = from_double_ended(vec![0, 1, 2].into_iter());
+
+    assert_eq!(pag.next(), Some(0));
+    assert_eq!(pag.previous(), Some(2));
+    assert_eq!(pag.next(), Some(1));
+    assert_eq!(pag.next(), None);
+}
@@ -28,7 +28,12 @@
 //! There are two common methods which can create paginators from a collection:
 //!
 //! - `paginate()`, which paginates over `&T`.
-//! - ~~`paginate_mut()`, which paginates over `&mut T`.~~ (not available because of lifetime shenanigans...)
+//! - `paginate_mut()`, which paginates over `&mut T`.
+//!
+//! The two aren't quite the same shape: `paginate_mut()` returns a
+//! [LendingPaginator](lending::LendingPaginator) rather than a [Paginator],
+//! because a `&mut` item has to stop borrowing before the next one can be
+//! requested. See the [lending] module for details.
 //!
 //! Various things in the standard library and in this crate may implement one or more of the two, where appropriate.
 //!
@@ -56,9 +61,18 @@
 //! 
 //! ```warning: unused result that must be used: paginators are lazy and do nothing unless consumed```
 
-use adapters::{Chain, ChainState, Enumerate, Map};
+use std::num::NonZeroUsize;
+
+use adapters::{
+    Chain, ChainState, Chunks, Enumerate, Filter, Map, Peek, Rev, SkipWhile, TakeWhile, Zip,
+};
+use iter_bridge::PaginatorIter;
 
 pub mod adapters;
+pub mod cursor;
+pub mod iter_bridge;
+pub mod lending;
+pub mod page;
 
 /// The core paginator trait.
 #[must_use = "paginators are lazy and do nothing unless consumed"]
@@ -106,6 +120,194 @@ pub trait Paginator {
             inner_b: other,
         }
     }
+
+    /// Adapts this paginator to one that groups elements into pages of up to
+    /// `page_size` elements, turning a flat collection into numbered pages
+    /// addressable with [Chunks::page_number], like Zola's `Pager::index`.
+    ///
+    /// The final page is yielded even if it's shorter than `page_size`, and
+    /// calling `previous` walks back a whole page at a time, re-emitting the
+    /// same grouping a matching `next` would have produced.
+    #[inline]
+    fn chunks(self, page_size: NonZeroUsize) -> Chunks<Self>
+    where
+        Self: Sized,
+    {
+        Chunks {
+            page_size: page_size.get(),
+            inner: self,
+            history: Vec::new(),
+        }
+    }
+
+    /// Adapts this paginator to one that walks in the opposite direction,
+    /// swapping `next` and `previous`. Every [Paginator] is already
+    /// double-ended, so no data needs reshaping — but since a fresh
+    /// paginator starts positioned before its first element rather than
+    /// after its last, the first call to `next`/`previous` must walk the
+    /// inner paginator all the way to its end before it can step backward,
+    /// an O(n) cost paid once and cached via `reached_end`.
+    #[inline]
+    fn rev(self) -> Rev<Self>
+    where
+        Self: Sized,
+    {
+        Rev {
+            inner: self,
+            reached_end: false,
+        }
+    }
+
+    /// Wraps this paginator so it also implements [Iterator] (via `next`),
+    /// letting it feed into the standard iterator ecosystem (`collect`,
+    /// `for` loops, etc). Deliberately does *not* implement
+    /// [DoubleEndedIterator]: that trait's `next_back` must pull an
+    /// unconsumed element from the tail so `next()`/`next_back()` converge
+    /// from both ends, but `Paginator::previous` only undoes the last
+    /// forward step — the two contracts don't match. Use
+    /// [PaginatorIter::previous] to step backward explicitly instead.
+    #[inline]
+    fn into_iter_bridge(self) -> PaginatorIter<Self>
+    where
+        Self: Sized,
+    {
+        PaginatorIter { inner: self }
+    }
+
+    /// Returns a new paginator that walks this and `other` in lockstep,
+    /// yielding pairs. Stops at the shorter side; if one side exhausts
+    /// before the other, the element already pulled from the longer side is
+    /// un-consumed with `previous` so nothing is silently dropped without a
+    /// partner.
+    #[inline]
+    fn zip<B>(self, other: B) -> Zip<Self, B>
+    where
+        Self: Sized,
+        B: Paginator,
+    {
+        Zip {
+            inner_a: self,
+            inner_b: other,
+        }
+    }
+
+    /// Adapts this paginator to one that can peek at the next or previous
+    /// element without consuming it, via [Peek::peek] and [Peek::peek_back].
+    #[inline]
+    fn peekable(self) -> Peek<Self>
+    where
+        Self: Sized,
+    {
+        Peek {
+            inner: self,
+            ahead: None,
+            behind: None,
+        }
+    }
+
+    /// Adapts this paginator to one that only yields elements matching
+    /// `predicate`. Correct under `previous` for free: since the inner
+    /// paginator's cursor is already symmetric, re-scanning backward finds
+    /// the same matching elements without needing a skip-buffer.
+    #[inline]
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&Self::Item) -> bool,
+    {
+        Filter {
+            inner: self,
+            predicate,
+        }
+    }
+
+    /// Adapts this paginator to one that discards elements matching
+    /// `predicate` from the front, stopping at (and keeping) the first
+    /// element that doesn't match. Once that element is found, further
+    /// elements pass through untested — but walking `previous` back before
+    /// it re-arms the skip, so a later `next` re-applies `predicate` instead
+    /// of blindly replaying the originally-skipped elements.
+    #[inline]
+    fn skip_while<P>(self, predicate: P) -> SkipWhile<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&Self::Item) -> bool,
+    {
+        SkipWhile {
+            inner: self,
+            predicate,
+            done: false,
+            position: 0,
+            skip_end: None,
+        }
+    }
+
+    /// Adapts this paginator to one that yields elements only while
+    /// `predicate` holds, stopping (without consuming) at the first element
+    /// that fails it. `previous` called right after that point returns the
+    /// last element that did pass, never the one that failed.
+    #[inline]
+    fn take_while<P>(self, predicate: P) -> TakeWhile<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&Self::Item) -> bool,
+    {
+        TakeWhile {
+            inner: self,
+            predicate,
+            done: false,
+        }
+    }
+
+    /// Steps this paginator forward by `n` elements, returning the `n`th one
+    /// (0-indexed), or `None` if fewer than `n + 1` elements remain.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        for _ in 0..n {
+            self.next()?;
+        }
+        self.next()
+    }
+
+    /// Repositions this paginator to `index`, as if it had just been created
+    /// and then advanced there with `next`. The default steps through the
+    /// intermediate elements; types that track their own index (like
+    /// [VecPag]) override this to reposition in O(1) instead.
+    fn seek(&mut self, index: usize) {
+        for _ in 0..index {
+            if self.next().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+/// Trait for paginators that know their remaining length upfront.
+/// [Paginator::seek] does not consult it: O(1) seeking comes from a type
+/// overriding `seek` itself, as [VecPag] does.
+pub trait ExactSizePaginator: Paginator {
+    /// The number of elements remaining in the forward direction.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no elements remaining in the forward direction.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Trait for paginators that can report their absolute position, building on
+/// [ExactSizePaginator] to give UI affordances like "jump to page N" a
+/// position to jump from and a length to jump within.
+pub trait SeekablePaginator: ExactSizePaginator {
+    /// The index of the element `next` would yield.
+    fn position(&self) -> usize;
+}
+
+impl<'pag, A: 'pag> SeekablePaginator for VecPag<'pag, A> {
+    #[inline]
+    fn position(&self) -> usize {
+        self.index
+    }
 }
 
 // #[test]
@@ -200,6 +402,22 @@ impl<'pag, A: 'pag> Paginator for VecPag<'pag, A> {
             self.index -= 1;
         })
     }
+
+    #[inline]
+    fn seek(&mut self, index: usize) {
+        // Clamp like the default, stepping implementation would: `next()`
+        // never advances past `items.len()`, so neither should jumping there
+        // directly (an out-of-range `seek` would otherwise make `len`
+        // underflow).
+        self.index = index.min(self.items.len());
+    }
+}
+
+impl<'pag, A: 'pag> ExactSizePaginator for VecPag<'pag, A> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.items.len() - self.index
+    }
 }
 
 impl<'pag, A: 'pag> Paginate<'pag> for Vec<A> {
@@ -229,3 +447,41 @@ fn test_vec_paginator() {
     assert_eq!(pag.previous(), Some(&0));
     assert_eq!(pag.previous(), None);
 }
+
+#[test]
+fn test_vec_paginator_seek_and_nth() {
+    let items = vec![0, 1, 2, 3];
+    let mut pag = items.paginate();
+
+    assert_eq!(pag.len(), 4);
+    pag.seek(2);
+    assert_eq!(pag.len(), 2);
+    assert_eq!(pag.next(), Some(&2));
+
+    let mut pag = items.paginate();
+    assert_eq!(pag.nth(2), Some(&2));
+    assert_eq!(pag.next(), Some(&3));
+}
+
+#[test]
+fn test_vec_paginator_seek_out_of_range() {
+    let items = vec![0, 1, 2, 3];
+    let mut pag = items.paginate();
+
+    pag.seek(100);
+    assert_eq!(pag.len(), 0);
+    assert_eq!(pag.next(), None);
+    assert_eq!(pag.previous(), Some(&3));
+}
+
+#[test]
+fn test_vec_paginator_position() {
+    let items = vec![0, 1, 2, 3];
+    let mut pag = items.paginate();
+
+    assert_eq!(pag.position(), 0);
+    pag.seek(3);
+    assert_eq!(pag.position(), 3);
+    assert_eq!(pag.next(), Some(&3));
+    assert_eq!(pag.position(), 4);
+}
@@ -0,0 +1,280 @@
+//! Relay-style cursor windowing: ask a [Paginator] for a bounded [Page] instead
+//! of stepping one element at a time.
+
+use super::Paginator;
+
+/// A windowed request into a [Paginator], modeled after the cursor arguments
+/// used by Relay-style APIs: `first`/`after` walk forward from a cursor,
+/// `last`/`before` walk backward from one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PageRequest {
+    pub first: Option<usize>,
+    pub last: Option<usize>,
+    pub after: Option<usize>,
+    pub before: Option<usize>,
+}
+
+/// A bounded window of items, with edge-detection flags telling the caller
+/// whether more elements exist on either side of the window. Shared by both
+/// offset-based windowing here and key-based windowing in
+/// [cursor::CursorPaginator::page](super::cursor::CursorPaginator::page).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<Item> {
+    pub items: Vec<Item>,
+    pub has_previous: bool,
+    pub has_next: bool,
+}
+
+/// Error returned when a page request's parameters contradict each other.
+/// Shared by both [PageOf::page] and
+/// [cursor::CursorPaginator::page](super::cursor::CursorPaginator::page).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageRequestError {
+    /// `first` and `last` were both specified; only one direction may be requested at a time.
+    ConflictingDirections,
+    /// `before` was specified without `last`, or `after` without `first`; a
+    /// cursor only makes sense paired with the count walking toward it.
+    MismatchedCursor,
+    /// `after` and `before` were both specified, but `after` lies at or past
+    /// `before`, leaving no valid window between them.
+    AfterPastBefore,
+}
+
+/// Extension trait providing [PageOf::page]. Kept separate from [Paginator]
+/// so the windowing logic lives next to the types it returns.
+pub trait PageOf: Paginator {
+    /// Consumes a window of elements described by `request`, seeking past
+    /// `after`/`before` first, then pulling one extra element in the
+    /// requested direction to detect `has_next`/`has_previous` before
+    /// discarding it.
+    fn page(mut self, request: PageRequest) -> Result<Page<Self::Item>, PageRequestError>
+    where
+        Self: Sized,
+    {
+        if request.first.is_some() && request.last.is_some() {
+            return Err(PageRequestError::ConflictingDirections);
+        }
+
+        if request.before.is_some() && request.last.is_none() {
+            return Err(PageRequestError::MismatchedCursor);
+        }
+
+        if request.after.is_some() && request.first.is_none() {
+            return Err(PageRequestError::MismatchedCursor);
+        }
+
+        let mut has_previous = false;
+        if let Some(after) = request.after {
+            for _ in 0..=after {
+                has_previous = self.next().is_some();
+                if !has_previous {
+                    break;
+                }
+            }
+        }
+
+        if let Some(last) = request.last {
+            let has_next = if let Some(before) = request.before {
+                let mut has_next = false;
+                for _ in 0..=before {
+                    has_next = self.next().is_some();
+                    if !has_next {
+                        break;
+                    }
+                }
+                if has_next {
+                    // The loop above probed one element past `before` to
+                    // detect it; back that step out so the cursor sits at
+                    // `before` again before the `previous()` walk below
+                    // collects `last` items ending there.
+                    self.previous();
+                }
+                has_next
+            } else {
+                // No `before` cursor: seek all the way to the true end, so
+                // `last` counts back from the actual tail instead of from
+                // wherever `after` happened to leave the cursor.
+                while self.next().is_some() {}
+                false
+            };
+
+            let mut items = Vec::with_capacity(last.saturating_add(1));
+            for _ in 0..=last {
+                match self.previous() {
+                    Some(item) => items.push(item),
+                    None => break,
+                }
+            }
+
+            let has_previous = items.len() > last;
+            if has_previous {
+                items.pop();
+            }
+            items.reverse();
+
+            return Ok(Page {
+                items,
+                has_previous,
+                has_next,
+            });
+        }
+
+        let first = request.first.unwrap_or(usize::MAX);
+        let mut items = Vec::new();
+        while items.len() < first {
+            match self.next() {
+                Some(item) => items.push(item),
+                None => break,
+            }
+        }
+
+        let has_next = self.next().is_some();
+
+        Ok(Page {
+            items,
+            has_previous,
+            has_next,
+        })
+    }
+}
+
+impl<P: Paginator> PageOf for P {}
+
+#[test]
+fn test_page_first_after() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2, 3, 4, 5];
+    let page = items
+        .paginate()
+        .page(PageRequest {
+            first: Some(2),
+            after: Some(1),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(page.items, vec![&2, &3]);
+    assert!(page.has_previous);
+    assert!(page.has_next);
+}
+
+#[test]
+fn test_page_last_before() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2, 3, 4, 5];
+    let page = items
+        .paginate()
+        .page(PageRequest {
+            last: Some(2),
+            before: Some(4),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(page.items, vec![&2, &3]);
+    assert!(page.has_previous);
+    assert!(page.has_next);
+}
+
+#[test]
+fn test_page_last_without_before() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2, 3, 4, 5];
+    let page = items
+        .paginate()
+        .page(PageRequest {
+            last: Some(2),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(page.items, vec![&4, &5]);
+    assert!(page.has_previous);
+    assert!(!page.has_next);
+}
+
+#[test]
+fn test_page_last_before_past_end() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2];
+    let page = items
+        .paginate()
+        .page(PageRequest {
+            last: Some(1),
+            before: Some(10),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(page.items, vec![&2]);
+    assert!(page.has_previous);
+    assert!(!page.has_next);
+}
+
+#[test]
+fn test_page_last_before_at_end() {
+    use crate::paginator::Paginate as _;
+
+    // `before` lands exactly on `items.len()`: nothing follows, so
+    // `has_next` must be `false` even though every step up to `before`
+    // itself succeeded.
+    let items = vec![0, 1, 2, 3];
+    let page = items
+        .paginate()
+        .page(PageRequest {
+            last: Some(2),
+            before: Some(4),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(page.items, vec![&2, &3]);
+    assert!(page.has_previous);
+    assert!(!page.has_next);
+}
+
+#[test]
+fn test_page_conflicting_directions() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2];
+    let result = items.paginate().page(PageRequest {
+        first: Some(1),
+        last: Some(1),
+        ..Default::default()
+    });
+
+    assert_eq!(result.err(), Some(PageRequestError::ConflictingDirections));
+}
+
+#[test]
+fn test_page_before_without_last_errors() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2, 3, 4, 5];
+    let result = items.paginate().page(PageRequest {
+        first: Some(2),
+        before: Some(2),
+        ..Default::default()
+    });
+
+    assert_eq!(result.err(), Some(PageRequestError::MismatchedCursor));
+}
+
+#[test]
+fn test_page_after_without_first_errors() {
+    use crate::paginator::Paginate as _;
+
+    let items = vec![0, 1, 2, 3, 4, 5];
+    let result = items.paginate().page(PageRequest {
+        last: Some(2),
+        after: Some(2),
+        ..Default::default()
+    });
+
+    assert_eq!(result.err(), Some(PageRequestError::MismatchedCursor));
+}